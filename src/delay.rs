@@ -0,0 +1,199 @@
+use super::audionode::*;
+use super::math::*;
+use super::*;
+use numeric_array::*;
+use std::marker::PhantomData;
+
+/// Ring buffer backing a fractional delay line. The capacity is rounded
+/// up to a power of two so the read and write indices can be wrapped with
+/// a bit mask. The write pointer advances one step per `write`.
+#[derive(Clone)]
+pub struct DelayBuffer<F: Real> {
+    buffer: Vec<F>,
+    mask: usize,
+    pos: usize,
+    /// Previous output of the allpass interpolator.
+    allpass_y: F,
+}
+
+impl<F: Real> DelayBuffer<F> {
+    /// Creates a buffer able to hold at least `max_delay` samples.
+    pub fn new(max_delay: usize) -> DelayBuffer<F> {
+        let capacity = (max_delay + 1).next_power_of_two();
+        DelayBuffer {
+            buffer: vec![F::zero(); capacity],
+            mask: capacity - 1,
+            pos: 0,
+            allpass_y: F::zero(),
+        }
+    }
+
+    /// Maximum delay in samples that can be read back.
+    pub fn max_delay(&self) -> usize {
+        self.buffer.len() - 1
+    }
+
+    /// Clears the buffer contents and resets the write pointer.
+    pub fn clear(&mut self) {
+        for x in self.buffer.iter_mut() {
+            *x = F::zero();
+        }
+        self.pos = 0;
+        self.allpass_y = F::zero();
+    }
+
+    /// Writes a sample at the write pointer and advances it.
+    #[inline]
+    pub fn write(&mut self, x: F) {
+        self.buffer[self.pos] = x;
+        self.pos = (self.pos + 1) & self.mask;
+    }
+
+    /// Returns the integer part (clamped) and fractional part of a delay
+    /// requested in samples.
+    #[inline]
+    fn split(&self, delay: F) -> (usize, F) {
+        let delay = clamp(F::zero(), F::from_f64(self.max_delay() as f64), delay);
+        let i = floor(delay.to_f64()) as usize;
+        (i, delay - F::from_f64(i as f64))
+    }
+
+    /// Reads a sample `delay` samples in the past using linear
+    /// interpolation between the two straddling samples.
+    #[inline]
+    pub fn read_linear(&self, delay: F) -> F {
+        let (i, frac) = self.split(delay);
+        let i0 = (self.pos + self.buffer.len() - 1 - i) & self.mask;
+        let i1 = (i0 + self.mask) & self.mask;
+        self.buffer[i0] + (self.buffer[i1] - self.buffer[i0]) * frac
+    }
+
+    /// Reads a sample `delay` samples in the past using first order
+    /// allpass interpolation. Allpass interpolation preserves magnitude
+    /// at the cost of some phase error and carries state between calls.
+    #[inline]
+    pub fn read_allpass(&mut self, delay: F) -> F {
+        let (i, frac) = self.split(delay);
+        let i0 = (self.pos + self.buffer.len() - 1 - i) & self.mask;
+        let i1 = (i0 + self.mask) & self.mask;
+        let eta = (F::one() - frac) / (F::one() + frac);
+        let y = eta * self.buffer[i0] + self.buffer[i1] - eta * self.allpass_y;
+        self.allpass_y = y;
+        y
+    }
+}
+
+/// Fractional delay line with feedback, a building block for echoes,
+/// choruses and flangers. Delay time and feedback are audio-rate inputs
+/// so the delay can be modulated by an LFO.
+/// - Input 0: input signal
+/// - Input 1: delay time (seconds)
+/// - Input 2: feedback amount
+/// - Output 0: delayed signal
+#[derive(Clone)]
+pub struct DelayLine<T: Float, F: Real> {
+    _marker: PhantomData<T>,
+    buffer: DelayBuffer<F>,
+    sample_rate: F,
+}
+
+impl<T: Float, F: Real> DelayLine<T, F> {
+    /// Creates a delay line with a maximum delay of `max_delay` seconds.
+    pub fn new(sample_rate: f64, max_delay: F) -> DelayLine<T, F> {
+        let max_samples = ceil(max_delay.to_f64() * sample_rate) as usize;
+        DelayLine {
+            _marker: PhantomData,
+            buffer: DelayBuffer::new(max_samples),
+            sample_rate: convert(sample_rate),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for DelayLine<T, F> {
+    const ID: u32 = 35;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.buffer.clear();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let delay: F = convert(input[1]) * self.sample_rate;
+        let feedback: F = convert(input[2]);
+        let delayed = self.buffer.read_linear(delay);
+        let x: F = convert(input[0]);
+        self.buffer.write(x + delayed * feedback);
+        [convert(delayed)].into()
+    }
+}
+
+/// Stereo fractional delay line with feedback, a building block for
+/// echoes, choruses and flangers that act on both channels at once. The
+/// left and right channels run through independent delay buffers but
+/// share a single delay time and feedback amount.
+/// - Input 0: left input signal
+/// - Input 1: right input signal
+/// - Input 2: delay time (seconds)
+/// - Input 3: feedback amount
+/// - Output 0: delayed left signal
+/// - Output 1: delayed right signal
+#[derive(Clone)]
+pub struct StereoDelayLine<T: Float, F: Real> {
+    _marker: PhantomData<T>,
+    left: DelayBuffer<F>,
+    right: DelayBuffer<F>,
+    sample_rate: F,
+}
+
+impl<T: Float, F: Real> StereoDelayLine<T, F> {
+    /// Creates a stereo delay line with a maximum delay of `max_delay` seconds.
+    pub fn new(sample_rate: f64, max_delay: F) -> StereoDelayLine<T, F> {
+        let max_samples = ceil(max_delay.to_f64() * sample_rate) as usize;
+        StereoDelayLine {
+            _marker: PhantomData,
+            left: DelayBuffer::new(max_samples),
+            right: DelayBuffer::new(max_samples),
+            sample_rate: convert(sample_rate),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for StereoDelayLine<T, F> {
+    const ID: u32 = 36;
+    type Sample = T;
+    type Inputs = typenum::U4;
+    type Outputs = typenum::U2;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.left.clear();
+        self.right.clear();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let delay: F = convert(input[2]) * self.sample_rate;
+        let feedback: F = convert(input[3]);
+        let delayed_left = self.left.read_linear(delay);
+        let delayed_right = self.right.read_linear(delay);
+        let x_left: F = convert(input[0]);
+        let x_right: F = convert(input[1]);
+        self.left.write(x_left + delayed_left * feedback);
+        self.right.write(x_right + delayed_right * feedback);
+        [convert(delayed_left), convert(delayed_right)].into()
+    }
+}
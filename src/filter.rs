@@ -48,6 +48,142 @@ impl<F: Real> BiquadCoefs<F> {
         let b2: F = -b0;
         BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
     }
+
+    /// Returns settings for a 2nd order lowpass filter (RBJ).
+    /// Cutoff is the center frequency in Hz. `q` is the filter Q.
+    pub fn lowpass(sample_rate: F, cutoff: F, q: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let w0: F = c(TAU) * cutoff / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha);
+        let b0: F = (c(1.0) - cw) * c(0.5) * a0r;
+        let b1: F = (c(1.0) - cw) * a0r;
+        let b2: F = b0;
+        let a1: F = c(-2.0) * cw * a0r;
+        let a2: F = (c(1.0) - alpha) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a 2nd order highpass filter (RBJ).
+    /// Cutoff is the center frequency in Hz. `q` is the filter Q.
+    pub fn highpass(sample_rate: F, cutoff: F, q: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let w0: F = c(TAU) * cutoff / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha);
+        let b0: F = (c(1.0) + cw) * c(0.5) * a0r;
+        let b1: F = -(c(1.0) + cw) * a0r;
+        let b2: F = b0;
+        let a1: F = c(-2.0) * cw * a0r;
+        let a2: F = (c(1.0) - alpha) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a 2nd order bandpass filter (RBJ) with 0 dB peak gain.
+    /// Center is the center frequency in Hz. `q` is the filter Q.
+    pub fn bandpass(sample_rate: F, center: F, q: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let w0: F = c(TAU) * center / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha);
+        let b0: F = alpha * a0r;
+        let b1: F = c(0.0);
+        let b2: F = -alpha * a0r;
+        let a1: F = c(-2.0) * cw * a0r;
+        let a2: F = (c(1.0) - alpha) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a 2nd order notch (bandreject) filter (RBJ).
+    /// Center is the notch frequency in Hz. `q` is the filter Q.
+    pub fn notch(sample_rate: F, center: F, q: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let w0: F = c(TAU) * center / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha);
+        let b0: F = a0r;
+        let b1: F = c(-2.0) * cw * a0r;
+        let b2: F = a0r;
+        let a1: F = b1;
+        let a2: F = (c(1.0) - alpha) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a 2nd order allpass filter (RBJ).
+    /// Center is the center frequency in Hz. `q` is the filter Q.
+    pub fn allpass(sample_rate: F, center: F, q: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let w0: F = c(TAU) * center / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha);
+        let b0: F = (c(1.0) - alpha) * a0r;
+        let b1: F = c(-2.0) * cw * a0r;
+        let b2: F = (c(1.0) + alpha) * a0r;
+        let a1: F = b1;
+        let a2: F = b0;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a peaking EQ filter (RBJ).
+    /// Center is the center frequency in Hz, `q` the filter Q
+    /// and `gain_db` the peak gain in decibels.
+    pub fn peaking(sample_rate: F, center: F, q: F, gain_db: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let a: F = pow(c(10.0), gain_db / c(40.0));
+        let w0: F = c(TAU) * center / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let a0r: F = c(1.0) / (c(1.0) + alpha / a);
+        let b0: F = (c(1.0) + alpha * a) * a0r;
+        let b1: F = c(-2.0) * cw * a0r;
+        let b2: F = (c(1.0) - alpha * a) * a0r;
+        let a1: F = c(-2.0) * cw * a0r;
+        let a2: F = (c(1.0) - alpha / a) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a low-shelf filter (RBJ).
+    /// Cutoff is the transition frequency in Hz, `q` the filter Q
+    /// and `gain_db` the shelf gain in decibels.
+    pub fn lowshelf(sample_rate: F, cutoff: F, q: F, gain_db: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let a: F = pow(c(10.0), gain_db / c(40.0));
+        let w0: F = c(TAU) * cutoff / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let ta: F = c(2.0) * sqrt(a) * alpha;
+        let a0r: F = c(1.0) / ((a + c(1.0)) + (a - c(1.0)) * cw + ta);
+        let b0: F = a * ((a + c(1.0)) - (a - c(1.0)) * cw + ta) * a0r;
+        let b1: F = c(2.0) * a * ((a - c(1.0)) - (a + c(1.0)) * cw) * a0r;
+        let b2: F = a * ((a + c(1.0)) - (a - c(1.0)) * cw - ta) * a0r;
+        let a1: F = c(-2.0) * ((a - c(1.0)) + (a + c(1.0)) * cw) * a0r;
+        let a2: F = ((a + c(1.0)) + (a - c(1.0)) * cw - ta) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
+
+    /// Returns settings for a high-shelf filter (RBJ).
+    /// Cutoff is the transition frequency in Hz, `q` the filter Q
+    /// and `gain_db` the shelf gain in decibels.
+    pub fn highshelf(sample_rate: F, cutoff: F, q: F, gain_db: F) -> BiquadCoefs<F> {
+        let c = F::from_f64;
+        let a: F = pow(c(10.0), gain_db / c(40.0));
+        let w0: F = c(TAU) * cutoff / sample_rate;
+        let cw: F = cos(w0);
+        let alpha: F = sin(w0) / (c(2.0) * q);
+        let ta: F = c(2.0) * sqrt(a) * alpha;
+        let a0r: F = c(1.0) / ((a + c(1.0)) - (a - c(1.0)) * cw + ta);
+        let b0: F = a * ((a + c(1.0)) + (a - c(1.0)) * cw + ta) * a0r;
+        let b1: F = c(-2.0) * a * ((a - c(1.0)) + (a + c(1.0)) * cw) * a0r;
+        let b2: F = a * ((a + c(1.0)) + (a - c(1.0)) * cw - ta) * a0r;
+        let a1: F = c(2.0) * ((a - c(1.0)) - (a + c(1.0)) * cw) * a0r;
+        let a2: F = ((a + c(1.0)) - (a - c(1.0)) * cw - ta) * a0r;
+        BiquadCoefs::<F> { a1, a2, b0, b1, b2 }
+    }
 }
 
 impl<F: Real> Lti for BiquadCoefs<F> {
@@ -379,3 +515,648 @@ impl<T: Float, F: Real> AudioNode for Declicker<T, F> {
         }
     }
 }
+
+/// 2nd order highpass filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: cutoff frequency (Hz)
+/// - Input 2: filter Q
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Highpass<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    cutoff: F,
+    q: F,
+}
+
+impl<T: Float, F: Real> Highpass<T, F> {
+    pub fn new(sample_rate: F) -> Highpass<T, F> {
+        Highpass {
+            biquad: Biquad::new(),
+            sample_rate,
+            cutoff: F::zero(),
+            q: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Highpass<T, F> {
+    const ID: u32 = 23;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+        self.q = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        if cutoff != self.cutoff || q != self.q {
+            self.biquad
+                .set_coefs(BiquadCoefs::highpass(self.sample_rate, cutoff, q));
+            self.cutoff = cutoff;
+            self.q = q;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// 2nd order bandpass filter (RBJ) with 0 dB peak gain.
+/// - Input 0: input signal
+/// - Input 1: center frequency (Hz)
+/// - Input 2: filter Q
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Bandpass<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    center: F,
+    q: F,
+}
+
+impl<T: Float, F: Real> Bandpass<T, F> {
+    pub fn new(sample_rate: F) -> Bandpass<T, F> {
+        Bandpass {
+            biquad: Biquad::new(),
+            sample_rate,
+            center: F::zero(),
+            q: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Bandpass<T, F> {
+    const ID: u32 = 24;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.center = F::zero();
+        self.q = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let center: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        if center != self.center || q != self.q {
+            self.biquad
+                .set_coefs(BiquadCoefs::bandpass(self.sample_rate, center, q));
+            self.center = center;
+            self.q = q;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// 2nd order notch (bandreject) filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: notch frequency (Hz)
+/// - Input 2: filter Q
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Notch<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    center: F,
+    q: F,
+}
+
+impl<T: Float, F: Real> Notch<T, F> {
+    pub fn new(sample_rate: F) -> Notch<T, F> {
+        Notch {
+            biquad: Biquad::new(),
+            sample_rate,
+            center: F::zero(),
+            q: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Notch<T, F> {
+    const ID: u32 = 25;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.center = F::zero();
+        self.q = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let center: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        if center != self.center || q != self.q {
+            self.biquad
+                .set_coefs(BiquadCoefs::notch(self.sample_rate, center, q));
+            self.center = center;
+            self.q = q;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// 2nd order allpass filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: center frequency (Hz)
+/// - Input 2: filter Q
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Allpass<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    center: F,
+    q: F,
+}
+
+impl<T: Float, F: Real> Allpass<T, F> {
+    pub fn new(sample_rate: F) -> Allpass<T, F> {
+        Allpass {
+            biquad: Biquad::new(),
+            sample_rate,
+            center: F::zero(),
+            q: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Allpass<T, F> {
+    const ID: u32 = 26;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.center = F::zero();
+        self.q = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let center: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        if center != self.center || q != self.q {
+            self.biquad
+                .set_coefs(BiquadCoefs::allpass(self.sample_rate, center, q));
+            self.center = center;
+            self.q = q;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// Peaking EQ filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: center frequency (Hz)
+/// - Input 2: filter Q
+/// - Input 3: peak gain (dB)
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Peaking<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    center: F,
+    q: F,
+    gain_db: F,
+}
+
+impl<T: Float, F: Real> Peaking<T, F> {
+    pub fn new(sample_rate: F) -> Peaking<T, F> {
+        Peaking {
+            biquad: Biquad::new(),
+            sample_rate,
+            center: F::zero(),
+            q: F::zero(),
+            gain_db: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Peaking<T, F> {
+    const ID: u32 = 27;
+    type Sample = T;
+    type Inputs = typenum::U4;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.center = F::zero();
+        self.q = F::zero();
+        self.gain_db = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let center: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        let gain_db: F = convert(input[3]);
+        if center != self.center || q != self.q || gain_db != self.gain_db {
+            self.biquad
+                .set_coefs(BiquadCoefs::peaking(self.sample_rate, center, q, gain_db));
+            self.center = center;
+            self.q = q;
+            self.gain_db = gain_db;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// Low-shelf filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: transition frequency (Hz)
+/// - Input 2: filter Q
+/// - Input 3: shelf gain (dB)
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Lowshelf<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    cutoff: F,
+    q: F,
+    gain_db: F,
+}
+
+impl<T: Float, F: Real> Lowshelf<T, F> {
+    pub fn new(sample_rate: F) -> Lowshelf<T, F> {
+        Lowshelf {
+            biquad: Biquad::new(),
+            sample_rate,
+            cutoff: F::zero(),
+            q: F::zero(),
+            gain_db: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Lowshelf<T, F> {
+    const ID: u32 = 28;
+    type Sample = T;
+    type Inputs = typenum::U4;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+        self.q = F::zero();
+        self.gain_db = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        let gain_db: F = convert(input[3]);
+        if cutoff != self.cutoff || q != self.q || gain_db != self.gain_db {
+            self.biquad
+                .set_coefs(BiquadCoefs::lowshelf(self.sample_rate, cutoff, q, gain_db));
+            self.cutoff = cutoff;
+            self.q = q;
+            self.gain_db = gain_db;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// High-shelf filter (RBJ).
+/// - Input 0: input signal
+/// - Input 1: transition frequency (Hz)
+/// - Input 2: filter Q
+/// - Input 3: shelf gain (dB)
+/// - Output 0: filtered signal
+#[derive(Copy, Clone)]
+pub struct Highshelf<T: Float, F: Real> {
+    biquad: Biquad<T, F>,
+    sample_rate: F,
+    cutoff: F,
+    q: F,
+    gain_db: F,
+}
+
+impl<T: Float, F: Real> Highshelf<T, F> {
+    pub fn new(sample_rate: F) -> Highshelf<T, F> {
+        Highshelf {
+            biquad: Biquad::new(),
+            sample_rate,
+            cutoff: F::zero(),
+            q: F::zero(),
+            gain_db: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Highshelf<T, F> {
+    const ID: u32 = 29;
+    type Sample = T;
+    type Inputs = typenum::U4;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.biquad.reset(sample_rate);
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+        self.q = F::zero();
+        self.gain_db = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        let gain_db: F = convert(input[3]);
+        if cutoff != self.cutoff || q != self.q || gain_db != self.gain_db {
+            self.biquad
+                .set_coefs(BiquadCoefs::highshelf(self.sample_rate, cutoff, q, gain_db));
+            self.cutoff = cutoff;
+            self.q = q;
+            self.gain_db = gain_db;
+        }
+        self.biquad.tick(&[input[0]].into())
+    }
+}
+
+/// Maximally-flat Butterworth lowpass filter of arbitrary even order,
+/// built by cascading 2nd order sections.
+/// The order must be even; each section uses a distinct Q so that the
+/// combined response is Butterworth. Roll-off is 6 dB/oct per order
+/// (e.g. order 4 is 24 dB/oct).
+/// - Input 0: input signal
+/// - Input 1: cutoff frequency (Hz)
+/// - Output 0: filtered signal
+#[derive(Clone)]
+pub struct ButterLowpassN<T: Float, F: Real> {
+    sections: Vec<Biquad<T, F>>,
+    qs: Vec<F>,
+    sample_rate: F,
+    cutoff: F,
+}
+
+/// Returns the section Qs for a Butterworth filter of the given even order.
+fn butterworth_qs<F: Real>(order: usize) -> Vec<F> {
+    let c = F::from_f64;
+    let m = order / 2;
+    (0..m)
+        .map(|k| {
+            let theta = c(PI) * c((2 * k + 1) as f64) / (c(2.0) * c(order as f64));
+            c(1.0) / (c(2.0) * cos(theta))
+        })
+        .collect()
+}
+
+impl<T: Float, F: Real> ButterLowpassN<T, F> {
+    /// Creates a Butterworth lowpass of the given even `order`.
+    pub fn new(sample_rate: F, order: usize) -> ButterLowpassN<T, F> {
+        assert!(order >= 2 && order % 2 == 0);
+        let qs = butterworth_qs(order);
+        ButterLowpassN {
+            sections: vec![Biquad::new(); qs.len()],
+            qs,
+            sample_rate,
+            cutoff: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for ButterLowpassN<T, F> {
+    const ID: u32 = 30;
+    type Sample = T;
+    type Inputs = typenum::U2;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        for section in self.sections.iter_mut() {
+            section.reset(sample_rate);
+        }
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        if cutoff != self.cutoff {
+            for (section, &q) in self.sections.iter_mut().zip(self.qs.iter()) {
+                section.set_coefs(BiquadCoefs::lowpass(self.sample_rate, cutoff, q));
+            }
+            self.cutoff = cutoff;
+        }
+        let mut x = input[0];
+        for section in self.sections.iter_mut() {
+            x = section.tick(&[x].into())[0];
+        }
+        [x].into()
+    }
+}
+
+/// Maximally-flat Butterworth highpass filter of arbitrary even order,
+/// built by cascading 2nd order sections (see `ButterLowpassN`).
+/// - Input 0: input signal
+/// - Input 1: cutoff frequency (Hz)
+/// - Output 0: filtered signal
+#[derive(Clone)]
+pub struct ButterHighpassN<T: Float, F: Real> {
+    sections: Vec<Biquad<T, F>>,
+    qs: Vec<F>,
+    sample_rate: F,
+    cutoff: F,
+}
+
+impl<T: Float, F: Real> ButterHighpassN<T, F> {
+    /// Creates a Butterworth highpass of the given even `order`.
+    pub fn new(sample_rate: F, order: usize) -> ButterHighpassN<T, F> {
+        assert!(order >= 2 && order % 2 == 0);
+        let qs = butterworth_qs(order);
+        ButterHighpassN {
+            sections: vec![Biquad::new(); qs.len()],
+            qs,
+            sample_rate,
+            cutoff: F::zero(),
+        }
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for ButterHighpassN<T, F> {
+    const ID: u32 = 31;
+    type Sample = T;
+    type Inputs = typenum::U2;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        for section in self.sections.iter_mut() {
+            section.reset(sample_rate);
+        }
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        if cutoff != self.cutoff {
+            for (section, &q) in self.sections.iter_mut().zip(self.qs.iter()) {
+                section.set_coefs(BiquadCoefs::highpass(self.sample_rate, cutoff, q));
+            }
+            self.cutoff = cutoff;
+        }
+        let mut x = input[0];
+        for section in self.sections.iter_mut() {
+            x = section.tick(&[x].into())[0];
+        }
+        [x].into()
+    }
+}
+
+/// Zero-delay-feedback (TPT) state variable filter.
+/// Unlike the Direct Form I biquads above it stays stable under fast
+/// cutoff and resonance modulation, making it well suited to expressive
+/// filter sweeps. All four responses are produced simultaneously.
+/// - Input 0: input signal
+/// - Input 1: cutoff frequency (Hz)
+/// - Input 2: resonance (Q)
+/// - Output 0: lowpass
+/// - Output 1: bandpass
+/// - Output 2: highpass
+/// - Output 3: notch
+#[derive(Copy, Clone)]
+pub struct Svf<T: Float, F: Real> {
+    _marker: std::marker::PhantomData<T>,
+    sample_rate: F,
+    cutoff: F,
+    q: F,
+    g: F,
+    k: F,
+    a1: F,
+    ic1eq: F,
+    ic2eq: F,
+}
+
+impl<T: Float, F: Real> Svf<T, F> {
+    pub fn new(sample_rate: F) -> Svf<T, F> {
+        Svf {
+            _marker: std::marker::PhantomData,
+            sample_rate,
+            cutoff: F::zero(),
+            q: F::zero(),
+            g: F::zero(),
+            k: F::zero(),
+            a1: F::zero(),
+            ic1eq: F::zero(),
+            ic2eq: F::zero(),
+        }
+    }
+
+    fn set_coefs(&mut self, cutoff: F, q: F) {
+        let c = F::from_f64;
+        self.g = tan(c(PI) * cutoff / self.sample_rate);
+        self.k = c(1.0) / q;
+        self.a1 = c(1.0) / (c(1.0) + self.g * (self.g + self.k));
+    }
+}
+
+impl<T: Float, F: Real> AudioNode for Svf<T, F> {
+    const ID: u32 = 34;
+    type Sample = T;
+    type Inputs = typenum::U3;
+    type Outputs = typenum::U4;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        if let Some(sr) = sample_rate {
+            self.sample_rate = convert(sr);
+        }
+        self.cutoff = F::zero();
+        self.q = F::zero();
+        self.ic1eq = F::zero();
+        self.ic2eq = F::zero();
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let cutoff: F = convert(input[1]);
+        let q: F = convert(input[2]);
+        if cutoff != self.cutoff || q != self.q {
+            self.set_coefs(cutoff, q);
+            self.cutoff = cutoff;
+            self.q = q;
+        }
+        let x: F = convert(input[0]);
+        let v1 = self.a1 * (self.ic1eq + self.g * (x - self.ic2eq));
+        let v2 = self.ic2eq + self.g * v1;
+        self.ic1eq = F::from_f64(2.0) * v1 - self.ic1eq;
+        self.ic2eq = F::from_f64(2.0) * v2 - self.ic2eq;
+        let lowpass = v2;
+        let bandpass = v1;
+        let highpass = x - self.k * v1 - v2;
+        let notch = highpass + lowpass;
+        [
+            convert(lowpass),
+            convert(bandpass),
+            convert(highpass),
+            convert(notch),
+        ]
+        .into()
+    }
+}
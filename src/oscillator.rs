@@ -3,6 +3,7 @@ use super::math::*;
 use super::*;
 use numeric_array::*;
 use std::marker::PhantomData;
+use std::sync::LazyLock;
 
 /// Sine oscillator.
 #[derive(Clone)]
@@ -52,3 +53,90 @@ impl<T: Float> AudioNode for SineComponent<T> {
         self.hash = hash;
     }
 }
+
+/// Number of entries in the `FastSine` lookup table (a power of two).
+const TABLE_SIZE: usize = 1 << 9;
+/// Phase (in radians) to table index scaling factor.
+const PHASE_SCALE: f64 = TABLE_SIZE as f64 / TAU;
+
+/// Shared cosine table with one guard sample appended for branch-free
+/// interpolation at the top of the table. Built once and shared by every
+/// `FastSine` instance, since the table is the same regardless of
+/// frequency, phase, or sample rate.
+static COSINE_TABLE: LazyLock<[f64; TABLE_SIZE + 1]> = LazyLock::new(|| {
+    let mut table = [0.0; TABLE_SIZE + 1];
+    for (i, entry) in table.iter_mut().enumerate() {
+        *entry = cos(TAU * i as f64 / TABLE_SIZE as f64);
+    }
+    table
+});
+
+/// Table-lookup sine oscillator. A faster, slightly less accurate
+/// drop-in replacement for `SineComponent`, trading a little precision
+/// for throughput when many oscillators run at once.
+/// - Input 0: frequency (Hz)
+/// - Output 0: sine wave
+#[derive(Clone)]
+pub struct FastSine<T: Float> {
+    _marker: PhantomData<T>,
+    phase: f64,
+    sample_duration: f64,
+    hash: u32,
+}
+
+impl<T: Float> FastSine<T> {
+    pub fn new() -> FastSine<T> {
+        FastSine {
+            _marker: PhantomData,
+            phase: 0.0,
+            sample_duration: 1.0 / DEFAULT_SR,
+            hash: 0,
+        }
+    }
+
+    /// Evaluates sine at `phase` radians using the shared cosine table
+    /// and linear interpolation. Sine is read as a quarter-turn phase
+    /// shift of cosine.
+    #[inline]
+    fn sine_at(&self, phase: f64) -> f64 {
+        // sin(x) = cos(x - PI/2); shift by a quarter of the table.
+        let x = phase * PHASE_SCALE - TABLE_SIZE as f64 * 0.25;
+        let x = x - floor(x / TABLE_SIZE as f64) * TABLE_SIZE as f64;
+        let i = x as usize;
+        let frac = x - i as f64;
+        let table = &*COSINE_TABLE;
+        table[i] + (table[i + 1] - table[i]) * frac
+    }
+}
+
+impl<T: Float> AudioNode for FastSine<T> {
+    const ID: u32 = 32;
+    type Sample = T;
+    type Inputs = typenum::U1;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, sample_rate: Option<f64>) {
+        self.phase = rnd(self.hash as u64) * TAU;
+        if let Some(sr) = sample_rate {
+            self.sample_duration = 1.0 / sr
+        };
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let frequency = input[0].to_f64();
+        self.phase += frequency * self.sample_duration * TAU;
+        if self.phase >= TAU {
+            self.phase -= TAU;
+        }
+        [convert(self.sine_at(self.phase))].into()
+    }
+
+    #[inline]
+    fn set_hash(&mut self, hash: u32) {
+        self.hash = hash;
+    }
+}
@@ -0,0 +1,96 @@
+use super::audionode::*;
+use super::math::*;
+use super::*;
+use numeric_array::*;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Static transfer curve applied by a `Shaper`.
+#[derive(Clone)]
+pub enum Shape {
+    /// Hyperbolic tangent soft saturation: `tanh(x)`.
+    Tanh,
+    /// Cubic soft clip `1.5x - 0.5x^3`, clamped to [-1, 1].
+    Softclip,
+    /// Hard clip to [-1, 1].
+    Hardclip,
+    /// Bitcrusher. The first argument is the number of amplitude bits,
+    /// the second the number of ticks to hold each sample (sample-rate
+    /// reduction). A hold of 1 means no sample-rate reduction.
+    Crush(f64, usize),
+    /// User supplied transfer function.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl Shape {
+    /// Builds a `Shape` from a closure.
+    pub fn custom(f: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Shape {
+        Shape::Custom(Arc::new(f))
+    }
+}
+
+/// Waveshaper / distortion stage applying a static transfer curve.
+/// The signal is multiplied by `drive` before shaping, so pushing the
+/// drive up drives the signal further into the nonlinearity.
+/// - Input 0: input signal
+/// - Input 1: drive
+/// - Output 0: shaped signal
+#[derive(Clone)]
+pub struct Shaper<T: Float> {
+    _marker: PhantomData<T>,
+    shape: Shape,
+    /// Held output sample for the bitcrusher.
+    held: f64,
+    /// Ticks remaining before the bitcrusher samples again.
+    count: usize,
+}
+
+impl<T: Float> Shaper<T> {
+    pub fn new(shape: Shape) -> Shaper<T> {
+        Shaper {
+            _marker: PhantomData,
+            shape,
+            held: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl<T: Float> AudioNode for Shaper<T> {
+    const ID: u32 = 33;
+    type Sample = T;
+    type Inputs = typenum::U2;
+    type Outputs = typenum::U1;
+
+    fn reset(&mut self, _sample_rate: Option<f64>) {
+        self.held = 0.0;
+        self.count = 0;
+    }
+
+    #[inline]
+    fn tick(
+        &mut self,
+        input: &Frame<Self::Sample, Self::Inputs>,
+    ) -> Frame<Self::Sample, Self::Outputs> {
+        let x = input[0].to_f64() * input[1].to_f64();
+        let y = match &self.shape {
+            Shape::Tanh => tanh(x),
+            Shape::Softclip => {
+                let x = clamp(-1.0, 1.0, x);
+                1.5 * x - 0.5 * x * x * x
+            }
+            Shape::Hardclip => clamp(-1.0, 1.0, x),
+            Shape::Crush(bits, hold) => {
+                if self.count == 0 {
+                    let levels = pow(2.0, *bits);
+                    self.held = round(x * levels) / levels;
+                    self.count = (*hold).max(1);
+                }
+                self.count -= 1;
+                self.held
+            }
+            Shape::Custom(f) => f(x),
+        };
+        [convert(y)].into()
+    }
+}